@@ -14,12 +14,78 @@ pub struct FractalLFO {
     depth: f32,
     /// Sample rate used to translate the rate in Hertz to a per-sample increment.
     sample_rate: f32,
+    /// Interpolation mode used to read values out of `fractal_points`.
+    interp: Interp,
+    /// Whether the table position wraps freely or holds at the end.
+    play_mode: PlayMode,
+}
+
+/// Selects how `FractalLFO::next` advances `position` past the end of the table.
+pub enum PlayMode {
+    /// Wrap back to the start, running freely like an oscillator.
+    Loop,
+    /// Clamp at the final table point and hold once reached, instead of
+    /// wrapping. Makes the fractal usable as a retriggerable envelope-like
+    /// contour synced to note-on or a clock tick, or as a randomized
+    /// sample-and-hold generator via its held terminal value.
+    OneShot,
+}
+
+/// Selects how `FractalLFO::next` reads values out of the fractal table between
+/// the two lattice points bracketing the current position.
+pub enum Interp {
+    /// Linear interpolation between the two adjacent table points.
+    Linear,
+    /// 4-point cubic interpolation using the two adjacent points plus one
+    /// neighbor on either side (the slope at each point is the difference of
+    /// its two neighbors), avoiding the slope discontinuities of
+    /// linear interpolation that are audible when modulating sensitive targets
+    /// like pitch or filter cutoff.
+    Cubic,
+}
+
+/// Selects the algorithm `FractalGenerator` uses to synthesize the fractal table.
+pub enum FractalMode {
+    /// Classic midpoint displacement, decaying the displacement amplitude over
+    /// `iterations` subdivision passes by `2^-roughness` per pass.
+    MidpointDisplacement {
+        iterations: usize,
+        /// Hurst exponent H (roughly 0.0..1.0) controlling amplitude decay:
+        /// low H keeps more energy in later, finer passes, producing jagged,
+        /// high-frequency-rich contours (fractal dimension closer to 2); high H
+        /// decays faster, producing smoother, more self-similar curves (fractal
+        /// dimension closer to 1). `1.0` reproduces the original fixed `x0.5` decay.
+        roughness: f32,
+    },
+    /// Sum of value-noise octaves (fractal Brownian motion), giving spectral
+    /// control over how smooth or jittery the resulting contour is.
+    Fbm {
+        /// Number of noise layers to sum.
+        octaves: usize,
+        /// Frequency multiplier applied to each successive octave (typically ~2.0).
+        lacunarity: f32,
+        /// Amplitude multiplier applied to each successive octave (0..1, lower = smoother).
+        persistence: f32,
+    },
 }
 
 impl FractalLFO {
-    /// Create a new `FractalLFO` with the given rate (Hz), depth, and sample rate.
-    pub fn new(rate: f32, depth: f32, sample_rate: f32, iterations: usize) -> Self {
-        let mut generator = FractalGenerator::new(iterations);
+    /// Create a new `FractalLFO` with the given rate (Hz), depth, sample rate, and
+    /// fractal generation `mode`.
+    ///
+    /// `seed` drives the fractal generator: the same seed always reproduces the
+    /// same fractal contour, while distinct seeds decorrelate multiple
+    /// `FractalLFO` instances from one another.
+    pub fn new(
+        rate: f32,
+        depth: f32,
+        sample_rate: f32,
+        mode: FractalMode,
+        seed: u64,
+        interp: Interp,
+        play_mode: PlayMode,
+    ) -> Self {
+        let mut generator = FractalGenerator::new(mode, seed);
         let fractal_points = generator.generate();
         let step = rate / sample_rate * (fractal_points.len() as f32);
 
@@ -29,6 +95,8 @@ impl FractalLFO {
             step,
             depth,
             sample_rate,
+            interp,
+            play_mode,
         }
     }
 
@@ -38,45 +106,107 @@ impl FractalLFO {
         self.step = rate / self.sample_rate * (self.fractal_points.len() as f32);
     }
 
+    /// Restart the traversal from the beginning of the table.
+    pub fn reset(&mut self) {
+        self.position = 0.0;
+    }
+
+    /// Jump to a normalized `[0, 1)` location in the table.
+    pub fn set_phase(&mut self, phase: f32) {
+        let len = self.fractal_points.len() as f32;
+        self.position = phase.rem_euclid(1.0) * len;
+    }
+
     /// Retrieve the next sample of the fractal LFO signal.
     pub fn next(&mut self) -> f32 {
         let len = self.fractal_points.len() as f32;
         let integer = self.position.floor() as usize % self.fractal_points.len();
-        let next_index = (integer + 1) % self.fractal_points.len();
         let frac = self.position - self.position.floor();
 
-        let current = self.fractal_points[integer];
-        let next = self.fractal_points[next_index];
-        let interpolated = current + frac * (next - current);
+        let interpolated = match self.interp {
+            Interp::Linear => {
+                let next_index = (integer + 1) % self.fractal_points.len();
+                let current = self.fractal_points[integer];
+                let next = self.fractal_points[next_index];
+                current + frac * (next - current)
+            }
+            Interp::Cubic => self.cubic_read(integer, frac),
+        };
 
-        self.position = (self.position + self.step) % len;
+        self.position = match self.play_mode {
+            PlayMode::Loop => (self.position + self.step) % len,
+            PlayMode::OneShot => (self.position + self.step).clamp(0.0, len - 1.0),
+        };
 
         interpolated * self.depth
     }
+
+    /// 4-point cubic read of the fractal table around index `i`, with tangents
+    /// `x2 - x0` at `x1` and `x3 - x1` at `x2` (twice the Catmull-Rom tangents),
+    /// wrapping neighbors modulo the table length since the LFO loops.
+    fn cubic_read(&self, i: usize, frac: f32) -> f32 {
+        let len = self.fractal_points.len();
+        let x0 = self.fractal_points[(i + len - 1) % len];
+        let x1 = self.fractal_points[i];
+        let x2 = self.fractal_points[(i + 1) % len];
+        let x3 = self.fractal_points[(i + 2) % len];
+
+        let a = x3 - x2 - x0 + x1;
+        let b = x0 - x1 - a;
+        let c = x2 - x0;
+        let d = x1;
+
+        ((a * frac + b) * frac + c) * frac + d
+    }
 }
 
-/// Helper struct that generates fractal noise using midpoint displacement.
+/// Helper struct that generates fractal noise using midpoint displacement or fBm.
 struct FractalGenerator {
-    iterations: usize,
+    mode: FractalMode,
+    seed: u64,
 }
 
+/// Number of samples in the fBm table, fixed regardless of octave count. Octaves
+/// whose lattice has about as many cells as the table has samples, or more, are
+/// undersampled.
+const FBM_TABLE_LEN: usize = 513;
+
+/// Number of lattice cells spanned by the lowest (coarsest) fBm octave.
+const FBM_BASE_LATTICE: usize = 4;
+
 impl FractalGenerator {
-    fn new(iterations: usize) -> Self {
-        Self { iterations }
+    fn new(mode: FractalMode, seed: u64) -> Self {
+        Self { mode, seed }
     }
 
     fn generate(&mut self) -> Vec<f32> {
+        match self.mode {
+            FractalMode::MidpointDisplacement {
+                iterations,
+                roughness,
+            } => self.generate_midpoint_displacement(iterations, roughness),
+            FractalMode::Fbm {
+                octaves,
+                lacunarity,
+                persistence,
+            } => self.generate_fbm(octaves, lacunarity, persistence),
+        }
+    }
+
+    fn generate_midpoint_displacement(&self, iterations: usize, roughness: f32) -> Vec<f32> {
         let mut points = vec![-1.0_f32, 1.0];
         let mut amplitude = 1.0_f32;
+        let decay = 2f32.powf(-roughness);
 
-        for _ in 0..self.iterations {
+        for octave in 0..iterations {
             let mut next_points = Vec::with_capacity(points.len() * 2 - 1);
 
-            for window in points.windows(2) {
+            for (point_index, window) in points.windows(2).enumerate() {
                 let left = window[0];
                 let right = window[1];
                 let midpoint = (left + right) * 0.5;
-                let displacement = random_offset(amplitude);
+                let displacement =
+                    random_offset(amplitude, self.seed, octave as u64, point_index as u64);
 
                 next_points.push(left);
                 next_points.push((midpoint + displacement).clamp(-1.0, 1.0));
@@ -84,18 +214,89 @@ impl FractalGenerator {
 
             next_points.push(*points.last().unwrap());
             points = next_points;
-            amplitude *= 0.5;
+            amplitude *= decay;
+        }
+
+        points
+    }
+
+    /// Synthesizes the table by summing cosine-interpolated value-noise octaves,
+    /// each at `FBM_BASE_LATTICE * lacunarity^o` lattice cells and `persistence^o`
+    /// gain, then normalizes by the total gain so the result stays in `[-1, 1]`.
+    fn generate_fbm(&self, octaves: usize, lacunarity: f32, persistence: f32) -> Vec<f32> {
+        let mut points = vec![0.0_f32; FBM_TABLE_LEN];
+        let mut gain_sum = 0.0_f32;
+
+        for octave in 0..octaves {
+            let lattice_count =
+                ((FBM_BASE_LATTICE as f32) * lacunarity.powi(octave as i32)).round() as usize;
+            let lattice_count = lattice_count.max(1);
+            let gain = persistence.powi(octave as i32);
+
+            for (i, point) in points.iter_mut().enumerate() {
+                let position = (i as f32 / FBM_TABLE_LEN as f32) * lattice_count as f32;
+                let lattice_index = position.floor() as usize;
+                let frac = position.fract();
+
+                let a = lattice_value(self.seed, octave as u64, (lattice_index % lattice_count) as u64);
+                let b = lattice_value(
+                    self.seed,
+                    octave as u64,
+                    ((lattice_index + 1) % lattice_count) as u64,
+                );
+
+                *point += cosine_interpolate(a, b, frac) * gain;
+            }
+
+            gain_sum += gain;
+        }
+
+        if gain_sum > 0.0 {
+            for point in &mut points {
+                *point = (*point / gain_sum).clamp(-1.0, 1.0);
+            }
         }
 
         points
     }
 }
 
+/// Smooth cosine interpolation between two lattice values, used by the fBm backend.
+#[inline]
+fn cosine_interpolate(a: f32, b: f32, t: f32) -> f32 {
+    let ft = (1.0 - (t * std::f32::consts::PI).cos()) * 0.5;
+    a * (1.0 - ft) + b * ft
+}
+
+/// Deterministic pseudo-random lattice value in `[-1, 1]` for the fBm backend,
+/// seeded from the LFO's `seed`, the octave index, and the lattice cell index.
+#[inline]
+fn lattice_value(seed: u64, octave: u64, lattice_index: u64) -> f32 {
+    let mixed = seed
+        .wrapping_add(octave.wrapping_mul(0x9E3779B97F4A7C15))
+        .wrapping_add(lattice_index.wrapping_mul(0xBF58476D1CE4E5B9));
+    splitmix64_unit(mixed) * 2.0 - 1.0
+}
+
 /// Pseudo-random offset for midpoint displacement.
+///
+/// Seeded per insertion by mixing the LFO's `seed` with the octave and point
+/// index, so every midpoint gets a distinct, reproducible displacement rather
+/// than the same value repeating across an octave.
+#[inline]
+fn random_offset(scale: f32, seed: u64, octave: u64, point_index: u64) -> f32 {
+    let mixed = seed
+        .wrapping_add(octave.wrapping_mul(0x9E3779B97F4A7C15))
+        .wrapping_add(point_index.wrapping_mul(0xBF58476D1CE4E5B9));
+    (splitmix64_unit(mixed) * 2.0 - 1.0) * scale
+}
+
+/// SplitMix64 step producing a uniform value in `[0.0, 1.0]` from the high bits.
 #[inline]
-fn random_offset(scale: f32) -> f32 {
-    // Simple deterministic generator using sine-based hashing.
-    // For production systems replace with a RNG suitable for your requirements.
-    let seed = scale.to_bits() as f32 * 12_345.6789;
-    (seed.sin() * 43758.5453).sin() * scale
+fn splitmix64_unit(state: u64) -> f32 {
+    let mut z = state.wrapping_add(0x9E3779B97F4A7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^= z >> 31;
+    ((z >> 40) as f32) / ((1u64 << 24) as f32)
 }